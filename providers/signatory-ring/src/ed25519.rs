@@ -4,23 +4,176 @@ use ring;
 use ring::signature::Ed25519KeyPair;
 use untrusted;
 
+use base64ct::{Base64UrlUnpadded, Encoding};
+use serde::{Deserialize, Serialize};
 use signatory::{
     ed25519::{FromSeed, PublicKey, Seed, Signature, Signer, Verifier},
     error::{Error, ErrorKind},
     pkcs8::FromPKCS8,
 };
 
+/// JSON Web Key (`"kty":"OKP"`, `"crv":"Ed25519"`) wire representation of an
+/// Ed25519 key, per RFC 8037. Prefer the [`Jwk`] trait's `to_jwk`/`from_jwk`
+/// methods over constructing this directly.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+struct JwkRepr {
+    kty: String,
+    crv: String,
+    x: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    d: Option<String>,
+}
+
+impl JwkRepr {
+    fn from_public_key(public_key: &PublicKey) -> Self {
+        JwkRepr {
+            kty: "OKP".to_owned(),
+            crv: "Ed25519".to_owned(),
+            x: Base64UrlUnpadded::encode_string(public_key.as_bytes()),
+            d: None,
+        }
+    }
+
+    fn to_public_key(&self) -> Result<PublicKey, Error> {
+        if self.kty != "OKP" || self.crv != "Ed25519" {
+            return Err(ErrorKind::KeyInvalid.into());
+        }
+
+        let bytes = Base64UrlUnpadded::decode_vec(&self.x)
+            .map_err(|_| Error::from(ErrorKind::KeyInvalid))?;
+
+        PublicKey::from_bytes(bytes).map_err(|_| ErrorKind::KeyInvalid.into())
+    }
+
+    fn to_seed(&self) -> Result<Seed, Error> {
+        let d = self
+            .d
+            .as_ref()
+            .ok_or_else(|| Error::from(ErrorKind::KeyInvalid))?;
+        let bytes =
+            Base64UrlUnpadded::decode_vec(d).map_err(|_| Error::from(ErrorKind::KeyInvalid))?;
+
+        Seed::from_bytes(bytes).map_err(|_| ErrorKind::KeyInvalid.into())
+    }
+}
+
+/// JSON Web Key (JWK) import/export, matching the `to_jwk`/`from_jwk` naming
+/// used on the ECDSA side (see `signatory::ecdsa::jwk`).
+pub trait Jwk: Sized {
+    /// Serialize as a JWK JSON string.
+    fn to_jwk(&self) -> String;
+
+    /// Parse from a JWK JSON string.
+    fn from_jwk(jwk: &str) -> Result<Self, Error>;
+}
+
+impl Jwk for PublicKey {
+    fn to_jwk(&self) -> String {
+        serde_json::to_string(&JwkRepr::from_public_key(self))
+            .expect("JWK serialization failure")
+    }
+
+    fn from_jwk(jwk: &str) -> Result<Self, Error> {
+        let repr: JwkRepr =
+            serde_json::from_str(jwk).map_err(|_| Error::from(ErrorKind::KeyInvalid))?;
+        repr.to_public_key()
+    }
+}
+
+impl Jwk for Ed25519Signer {
+    /// Serialize this signer's public key (and its seed, if known) as a JWK
+    /// JSON string.
+    fn to_jwk(&self) -> String {
+        let public_key = self.public_key().expect("invalid signing key");
+        let mut repr = JwkRepr::from_public_key(&public_key);
+
+        if let Some(seed) = &self.1 {
+            repr.d = Some(Base64UrlUnpadded::encode_string(&seed.0));
+        }
+
+        serde_json::to_string(&repr).expect("JWK serialization failure")
+    }
+
+    /// Parse a signer out of a JWK JSON string containing a private `d`.
+    fn from_jwk(jwk: &str) -> Result<Self, Error> {
+        let repr: JwkRepr =
+            serde_json::from_str(jwk).map_err(|_| Error::from(ErrorKind::KeyInvalid))?;
+        let seed = repr.to_seed()?;
+        Ok(Self::from_seed(seed))
+    }
+}
+
+/// OID for the Ed25519 signature algorithm (RFC 8410), used as the
+/// `AlgorithmIdentifier` in an Ed25519 `SubjectPublicKeyInfo`.
+const ED25519_OID: pkcs8::ObjectIdentifier = pkcs8::ObjectIdentifier::new("1.3.101.112");
+
+/// SubjectPublicKeyInfo (SPKI) DER/PEM encoding for an Ed25519 public key.
+pub trait Spki: Sized {
+    /// Encode as a DER-encoded `SubjectPublicKeyInfo`.
+    fn to_public_key_der(&self) -> Vec<u8>;
+
+    /// Encode as a `-----BEGIN PUBLIC KEY-----` PEM document.
+    fn to_public_key_pem(&self) -> String;
+
+    /// Parse from a DER-encoded `SubjectPublicKeyInfo`.
+    fn from_public_key_der(der: &[u8]) -> Result<Self, Error>;
+
+    /// Parse from a `-----BEGIN PUBLIC KEY-----` PEM document.
+    fn from_public_key_pem(pem: &str) -> Result<Self, Error>;
+}
+
+impl Spki for PublicKey {
+    fn to_public_key_der(&self) -> Vec<u8> {
+        pkcs8::SubjectPublicKeyInfo {
+            algorithm: pkcs8::AlgorithmIdentifier {
+                oid: ED25519_OID,
+                parameters: None,
+            },
+            subject_public_key: self.as_bytes(),
+        }
+        .to_vec()
+    }
+
+    fn to_public_key_pem(&self) -> String {
+        pem::encode(&pem::Pem {
+            tag: "PUBLIC KEY".to_owned(),
+            contents: self.to_public_key_der(),
+        })
+    }
+
+    fn from_public_key_der(der: &[u8]) -> Result<Self, Error> {
+        let spki = pkcs8::SubjectPublicKeyInfo::try_from(der)
+            .map_err(|_| Error::from(ErrorKind::KeyInvalid))?;
+
+        if spki.algorithm.oid != ED25519_OID {
+            return Err(ErrorKind::KeyInvalid.into());
+        }
+
+        PublicKey::from_bytes(spki.subject_public_key).map_err(|_| ErrorKind::KeyInvalid.into())
+    }
+
+    fn from_public_key_pem(pem_str: &str) -> Result<Self, Error> {
+        let parsed = pem::parse(pem_str).map_err(|_| Error::from(ErrorKind::KeyInvalid))?;
+        Self::from_public_key_der(&parsed.contents)
+    }
+}
+
 /// Ed25519 signature provider for *ring*
-pub struct Ed25519Signer(Ed25519KeyPair);
+///
+/// Keeps the unexpanded seed alongside the *ring* keypair (when known) so
+/// that [`Ed25519Signer::to_jwk`] can round-trip the private `d` member;
+/// signers constructed from a PKCS#8 document have no seed to recover it
+/// from, so that half is `None`.
+pub struct Ed25519Signer(Ed25519KeyPair, Option<Seed>);
 
 impl FromSeed for Ed25519Signer {
     /// Create a new Ed25519Signer from an unexpanded seed value
     fn from_seed<S: Into<Seed>>(seed: S) -> Self {
-        let keypair = Ed25519KeyPair::from_seed_unchecked(untrusted::Input::from(
-            &seed.into().0[..],
-        )).unwrap();
+        let seed = seed.into();
+        let keypair =
+            Ed25519KeyPair::from_seed_unchecked(untrusted::Input::from(&seed.0[..])).unwrap();
 
-        Ed25519Signer(keypair)
+        Ed25519Signer(keypair, Some(seed))
     }
 }
 
@@ -30,7 +183,7 @@ impl FromPKCS8 for Ed25519Signer {
         let keypair = Ed25519KeyPair::from_pkcs8(untrusted::Input::from(pkcs8_bytes))
             .map_err(|_| err!(KeyInvalid, "invalid PKCS#8 private key"))?;
 
-        Ok(Ed25519Signer(keypair))
+        Ok(Ed25519Signer(keypair, None))
     }
 }
 
@@ -61,6 +214,33 @@ impl Verifier for Ed25519Verifier {
 
 #[cfg(test)]
 mod tests {
-    use super::{Ed25519Signer, Ed25519Verifier};
+    use super::{Ed25519Signer, Ed25519Verifier, Jwk, PublicKey, Spki};
+    use signatory::ed25519::FromSeed;
+
     ed25519_tests!(Ed25519Signer, Ed25519Verifier);
+
+    #[test]
+    fn jwk_roundtrip() {
+        let signer = Ed25519Signer::from_seed([42u8; 32]);
+        let public_key = signer.public_key().unwrap();
+
+        let jwk = signer.to_jwk();
+        let decoded = Ed25519Signer::from_jwk(&jwk).unwrap();
+        assert_eq!(decoded.public_key().unwrap(), public_key);
+
+        let public_jwk = public_key.to_jwk();
+        assert_eq!(PublicKey::from_jwk(&public_jwk).unwrap(), public_key);
+    }
+
+    #[test]
+    fn spki_der_and_pem_roundtrip() {
+        let signer = Ed25519Signer::from_seed([7u8; 32]);
+        let public_key = signer.public_key().unwrap();
+
+        let der = public_key.to_public_key_der();
+        assert_eq!(PublicKey::from_public_key_der(&der).unwrap(), public_key);
+
+        let pem = public_key.to_public_key_pem();
+        assert_eq!(PublicKey::from_public_key_pem(&pem).unwrap(), public_key);
+    }
 }
@@ -8,6 +8,7 @@ use ring::signature::{
     ECDSA_P256_SHA256_FIXED, ECDSA_P256_SHA256_FIXED_SIGNING,
 };
 use signatory::{
+    ecdsa::{recoverable::RecoverableSignature, RecoveryId},
     public_key::PublicKeyed,
     signature::{self, Signature},
 };
@@ -97,6 +98,64 @@ impl signature::Signer<FixedSignature> for Signer<FixedSignature> {
     }
 }
 
+impl Signer<FixedSignature> {
+    /// Sign `msg`, producing a [`RecoverableSignature`] of the same generic
+    /// type used by `secp256k1` (see `signatory::ecdsa::recoverable`).
+    ///
+    /// *ring* doesn't expose the ephemeral nonce used while signing, so
+    /// unlike a software implementation we can't compute the recovery ID
+    /// directly from the parity of `R`. Instead we sign normally and use
+    /// trial recovery: try each of the 4 candidate recovery IDs against the
+    /// signature we already produced and keep the one that recovers our
+    /// own public key.
+    pub fn sign_recoverable(
+        &self,
+        msg: &[u8],
+    ) -> Result<RecoverableSignature<p256::NistP256>, signature::Error> {
+        use signature::Signer as _;
+
+        let fixed_signature: FixedSignature = self.sign(msg);
+        let ecdsa_signature = p256::ecdsa::Signature::from_der(fixed_signature.as_ref())
+            .or_else(|_| p256::ecdsa::Signature::try_from(fixed_signature.as_ref()))
+            .map_err(|_| signature::Error::new())?;
+
+        let public_key = self.public_key()?;
+        let verifying_key = p256::ecdsa::VerifyingKey::from_sec1_bytes(public_key.as_ref())
+            .map_err(|_| signature::Error::new())?;
+
+        for recovery_byte in 0..4 {
+            let recovery_id = RecoveryId::from_byte(recovery_byte).expect("0..4 is a valid recovery byte range");
+
+            let recovered = p256::ecdsa::VerifyingKey::recover_from_msg(
+                msg,
+                &ecdsa_signature,
+                recovery_id,
+            );
+
+            if recovered.map_or(false, |candidate| candidate == verifying_key) {
+                return Ok(RecoverableSignature::new(ecdsa_signature, recovery_id));
+            }
+        }
+
+        Err(signature::Error::new())
+    }
+}
+
+/// Recover the public key of the signer of `msg` from a P-256
+/// [`RecoverableSignature`] alone.
+#[cfg(feature = "std")]
+pub fn recover_verifier(
+    msg: &[u8],
+    signature: &RecoverableSignature<p256::NistP256>,
+) -> Result<PublicKey, signature::Error> {
+    let candidate = signature
+        .recover_verifier(msg)
+        .map_err(|_| signature::Error::new())?;
+
+    PublicKey::from_bytes(candidate.to_encoded_point(false).as_bytes())
+        .map_err(|_| signature::Error::new())
+}
+
 /// NIST P-256 ECDSA verifier
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Verifier(PublicKey);
@@ -165,4 +224,18 @@ mod tests {
             "expected bad signature to cause validation error!"
         );
     }
+
+    #[test]
+    pub fn recovers_signer_public_key() {
+        use super::{recover_verifier, FixedSignature};
+
+        let vector = &SHA256_FIXED_SIZE_TEST_VECTORS[0];
+        let signer: Signer<FixedSignature> =
+            Signer::from_pkcs8(&vector.to_pkcs8(TestVectorAlgorithm::NistP256)).unwrap();
+
+        let signature = signer.sign_recoverable(vector.msg).unwrap();
+        let recovered = recover_verifier(vector.msg, &signature).unwrap();
+
+        assert_eq!(recovered, signer.public_key().unwrap());
+    }
 }
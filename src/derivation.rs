@@ -0,0 +1,296 @@
+//! SLIP-0010 hierarchical deterministic key derivation along BIP32-style
+//! paths (e.g. `m/44'/0'/0'/0/0`).
+
+use crate::{Error, Result};
+use alloc::vec::Vec;
+use core::str::FromStr;
+use hmac::{Hmac, Mac};
+use sha2::Sha512;
+
+type HmacSha512 = Hmac<Sha512>;
+
+const HARDENED_FLAG: u32 = 1 << 31;
+
+/// A single index within a [`DerivationPath`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct ChildNumber(u32);
+
+impl ChildNumber {
+    /// Construct a hardened child index (rendered as `i'`).
+    pub fn hardened(index: u32) -> Self {
+        ChildNumber(index | HARDENED_FLAG)
+    }
+
+    /// Construct a non-hardened child index.
+    pub fn normal(index: u32) -> Self {
+        ChildNumber(index & !HARDENED_FLAG)
+    }
+
+    /// Whether this is a hardened index.
+    pub fn is_hardened(self) -> bool {
+        self.0 & HARDENED_FLAG != 0
+    }
+
+    /// `ser32(i)`: the big-endian 4-byte encoding used as HMAC input.
+    fn ser32(self) -> [u8; 4] {
+        self.0.to_be_bytes()
+    }
+}
+
+impl FromStr for ChildNumber {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (digits, hardened) = match s.strip_suffix('\'').or_else(|| s.strip_suffix('h')) {
+            Some(digits) => (digits, true),
+            None => (s, false),
+        };
+
+        let index: u32 = digits.parse().map_err(|_| Error::DerivationPath)?;
+        Ok(if hardened {
+            Self::hardened(index)
+        } else {
+            Self::normal(index)
+        })
+    }
+}
+
+/// A BIP32-style derivation path, e.g. `m/44'/0'/0'/0/0`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DerivationPath(Vec<ChildNumber>);
+
+impl FromStr for DerivationPath {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut components = s.split('/');
+
+        if components.next() != Some("m") {
+            return Err(Error::DerivationPath);
+        }
+
+        components
+            .map(ChildNumber::from_str)
+            .collect::<Result<Vec<_>>>()
+            .map(DerivationPath)
+    }
+}
+
+/// Which curve's HMAC seed key and child-derivation rule to use.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Curve {
+    /// Ed25519 — SLIP-0010 requires this curve's paths to be hardened-only.
+    Ed25519,
+    /// secp256k1
+    Secp256k1,
+    /// NIST P-256
+    NistP256,
+}
+
+impl Curve {
+    fn hmac_seed_key(self) -> &'static [u8] {
+        match self {
+            Curve::Ed25519 => b"ed25519 seed",
+            Curve::Secp256k1 => b"Bitcoin seed",
+            Curve::NistP256 => b"Nist256p1 seed",
+        }
+    }
+}
+
+/// A derived SLIP-0010 key: 32 bytes of key material plus the chain code
+/// needed to derive further children.
+pub struct ExtendedKey {
+    /// Derived key material: an Ed25519 seed, or a secp256k1/P-256 scalar.
+    ///
+    /// There's no Ed25519 signer in this crate to convert this into, so for
+    /// `Curve::Ed25519` this field *is* the usable signer input — hand it
+    /// directly to whatever Ed25519 implementation you're using (e.g.
+    /// `FromSeed::from_seed`). For `Curve::Secp256k1`/`Curve::NistP256`,
+    /// prefer [`ExtendedKey::to_secp256k1_signing_key`]/
+    /// [`ExtendedKey::to_nistp256_signing_key`] over using this field raw.
+    pub key: [u8; 32],
+
+    /// Chain code (`I_R`), used to derive further children.
+    pub chain_code: [u8; 32],
+}
+
+impl ExtendedKey {
+    /// Convert this derived key into a usable secp256k1 ECDSA signing key.
+    #[cfg(feature = "secp256k1")]
+    pub fn to_secp256k1_signing_key(&self) -> Result<crate::ecdsa::secp256k1::SigningKey> {
+        crate::ecdsa::secp256k1::SigningKey::from_bytes(&self.key)
+    }
+
+    /// Convert this derived key into a usable P-256 ECDSA signing key.
+    #[cfg(feature = "nistp256")]
+    pub fn to_nistp256_signing_key(&self) -> Result<crate::ecdsa::nistp256::SigningKey> {
+        crate::ecdsa::nistp256::SigningKey::from_bytes(&self.key)
+    }
+}
+
+fn hmac_sha512(key: &[u8], msg: &[&[u8]]) -> [u8; 64] {
+    let mut mac = HmacSha512::new_from_slice(key).expect("HMAC-SHA512 accepts any key length");
+
+    for part in msg {
+        mac.update(part);
+    }
+
+    mac.finalize().into_bytes().into()
+}
+
+/// Derive a child key from a master `seed` by walking `path`, per SLIP-0010.
+pub fn derive(curve: Curve, seed: &[u8], path: &DerivationPath) -> Result<ExtendedKey> {
+    let i = hmac_sha512(curve.hmac_seed_key(), &[seed]);
+    let mut key: [u8; 32] = i[..32].try_into().expect("HMAC-SHA512 output is 64 bytes");
+    let mut chain_code: [u8; 32] = i[32..].try_into().expect("HMAC-SHA512 output is 64 bytes");
+
+    for &index in &path.0 {
+        if matches!(curve, Curve::Ed25519) && !index.is_hardened() {
+            return Err(Error::DerivationPath);
+        }
+
+        let i = if index.is_hardened() {
+            hmac_sha512(&chain_code, &[&[0x00], &key, &index.ser32()])
+        } else {
+            let point = public_point(curve, &key)?;
+            hmac_sha512(&chain_code, &[&point, &index.ser32()])
+        };
+
+        let (i_l, i_r) = i.split_at(32);
+
+        key = match curve {
+            Curve::Ed25519 => i_l.try_into().expect("I_L half of HMAC-SHA512 output is 32 bytes"),
+            Curve::Secp256k1 | Curve::NistP256 => add_scalars_mod_n(curve, &key, i_l)?,
+        };
+        chain_code = i_r.try_into().expect("I_R half of HMAC-SHA512 output is 32 bytes");
+    }
+
+    Ok(ExtendedKey { key, chain_code })
+}
+
+/// SEC1 compressed-point encoding of `key · G`, used as HMAC input for
+/// non-hardened secp256k1/P-256 derivation.
+fn public_point(curve: Curve, key: &[u8; 32]) -> Result<[u8; 33]> {
+    use elliptic_curve::{
+        sec1::ToEncodedPoint,
+        {Curve as _, Group},
+    };
+
+    match curve {
+        Curve::Secp256k1 => {
+            let scalar =
+                Option::<k256::Scalar>::from(k256::Scalar::from_repr((*key).into()))
+                    .ok_or(Error::DerivationPath)?;
+            let point = (k256::ProjectivePoint::GENERATOR * scalar).to_affine();
+            Ok(point
+                .to_encoded_point(true)
+                .as_bytes()
+                .try_into()
+                .expect("compressed SEC1 point is 33 bytes"))
+        }
+        Curve::NistP256 => {
+            let scalar =
+                Option::<p256::Scalar>::from(p256::Scalar::from_repr((*key).into()))
+                    .ok_or(Error::DerivationPath)?;
+            let point = (p256::ProjectivePoint::GENERATOR * scalar).to_affine();
+            Ok(point
+                .to_encoded_point(true)
+                .as_bytes()
+                .try_into()
+                .expect("compressed SEC1 point is 33 bytes"))
+        }
+        Curve::Ed25519 => unreachable!("Ed25519 derivation is hardened-only"),
+    }
+}
+
+/// `(parent_key + I_L) mod n`
+fn add_scalars_mod_n(curve: Curve, parent_key: &[u8; 32], i_l: &[u8]) -> Result<[u8; 32]> {
+    let i_l: [u8; 32] = i_l.try_into().expect("I_L input is 32 bytes");
+
+    match curve {
+        Curve::Secp256k1 => {
+            let parent = Option::<k256::Scalar>::from(k256::Scalar::from_repr((*parent_key).into()))
+                .ok_or(Error::DerivationPath)?;
+            let delta = Option::<k256::Scalar>::from(k256::Scalar::from_repr(i_l.into()))
+                .ok_or(Error::DerivationPath)?;
+            Ok((parent + delta).to_bytes().into())
+        }
+        Curve::NistP256 => {
+            let parent = Option::<p256::Scalar>::from(p256::Scalar::from_repr((*parent_key).into()))
+                .ok_or(Error::DerivationPath)?;
+            let delta = Option::<p256::Scalar>::from(p256::Scalar::from_repr(i_l.into()))
+                .ok_or(Error::DerivationPath)?;
+            Ok((parent + delta).to_bytes().into())
+        }
+        Curve::Ed25519 => unreachable!("Ed25519 derivation is hardened-only"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Curve, DerivationPath};
+    use core::str::FromStr;
+
+    fn hex(s: &str) -> alloc::vec::Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    // Official SLIP-0010/BIP32 test vector 1 master key, from
+    // https://github.com/satoshilabs/slips/blob/master/slip-0010.md
+    #[test]
+    fn secp256k1_master_key_test_vector() {
+        let seed = hex("000102030405060708090a0b0c0d0e0f");
+        let path = DerivationPath::from_str("m").unwrap();
+        let extended_key = super::derive(Curve::Secp256k1, &seed, &path).unwrap();
+
+        assert_eq!(
+            extended_key.key.as_slice(),
+            hex("e8f32e723decf4051aefac8e2c93c9c5b214313817cdb01a1494b917c8436b35").as_slice()
+        );
+        assert_eq!(
+            extended_key.chain_code.as_slice(),
+            hex("873dff81c02f525623fd1fe5167eac3a55a049de3d314bb42ee227ffed37d508").as_slice()
+        );
+    }
+
+    // Official SLIP-0010/BIP32 test vector 1, child `m/0'`, from
+    // https://github.com/satoshilabs/slips/blob/master/slip-0010.md
+    #[test]
+    fn secp256k1_hardened_child_test_vector() {
+        let seed = hex("000102030405060708090a0b0c0d0e0f");
+        let path = DerivationPath::from_str("m/0'").unwrap();
+        let extended_key = super::derive(Curve::Secp256k1, &seed, &path).unwrap();
+
+        assert_eq!(
+            extended_key.key.as_slice(),
+            hex("edb2e14f9ee77d26dd93b4ecede8d16ed408ce149b6cd80b0715a2d911a0afea").as_slice()
+        );
+        assert_eq!(
+            extended_key.chain_code.as_slice(),
+            hex("47fdacbd0f1097043b78c63c20c34ef4ed9a111d980047ad16282c7ae6236141").as_slice()
+        );
+    }
+
+    #[test]
+    fn derivation_is_deterministic() {
+        let seed = hex("000102030405060708090a0b0c0d0e0f");
+        let path = DerivationPath::from_str("m/0'/1'").unwrap();
+
+        let a = super::derive(Curve::Secp256k1, &seed, &path).unwrap();
+        let b = super::derive(Curve::Secp256k1, &seed, &path).unwrap();
+
+        assert_eq!(a.key, b.key);
+        assert_eq!(a.chain_code, b.chain_code);
+    }
+
+    #[test]
+    fn ed25519_rejects_non_hardened_path() {
+        let seed = hex("000102030405060708090a0b0c0d0e0f");
+        let path = DerivationPath::from_str("m/0").unwrap();
+
+        assert!(super::derive(Curve::Ed25519, &seed, &path).is_err());
+    }
+}
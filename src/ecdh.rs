@@ -0,0 +1,84 @@
+//! Elliptic Curve Diffie-Hellman (ECDH) key agreement.
+//!
+//! This mirrors the `ecdh` module pattern found throughout the secp256k1
+//! ecosystem: a secret key (possibly ephemeral, generated for one-shot use)
+//! is combined with a peer's [`PublicKey`](elliptic_curve::PublicKey) to
+//! derive a [`SharedSecret`].
+
+use elliptic_curve::{ecdh, rand_core::CryptoRngCore, CurveArithmetic, PublicKey};
+
+#[cfg(feature = "secp256k1")]
+pub use k256::Secp256k1;
+
+#[cfg(feature = "nistp256")]
+pub use p256::NistP256;
+
+/// A secret scalar generated for a single ECDH exchange and then discarded.
+///
+/// Wraps [`elliptic_curve::ecdh::EphemeralSecret`], which zeroizes its
+/// contents on drop.
+pub struct EphemeralSecret<C: CurveArithmetic>(ecdh::EphemeralSecret<C>);
+
+impl<C: CurveArithmetic> EphemeralSecret<C> {
+    /// Generate a new ephemeral secret using the given cryptographically
+    /// secure random number generator (e.g. `rand_core::OsRng`), for
+    /// one-shot ECDH.
+    pub fn generate(rng: &mut impl CryptoRngCore) -> Self {
+        Self(ecdh::EphemeralSecret::random(rng))
+    }
+
+    /// Compute this secret's public key, to be sent to the peer.
+    pub fn public_key(&self) -> PublicKey<C> {
+        self.0.public_key()
+    }
+
+    /// Perform a Diffie-Hellman key agreement with a peer's public key,
+    /// producing a [`SharedSecret`].
+    pub fn diffie_hellman(&self, peer_public_key: &PublicKey<C>) -> SharedSecret<C> {
+        SharedSecret(self.0.diffie_hellman(peer_public_key))
+    }
+}
+
+/// The x-coordinate of `secret · peer_point`, i.e. the output of a
+/// Diffie-Hellman key agreement. Zeroizes on drop.
+pub struct SharedSecret<C: CurveArithmetic>(ecdh::SharedSecret<C>);
+
+impl<C: CurveArithmetic> SharedSecret<C> {
+    /// Shared secret bytes, i.e. the serialized x-coordinate of the
+    /// computed point.
+    pub fn raw_secret_bytes(&self) -> &elliptic_curve::FieldBytes<C> {
+        self.0.raw_secret_bytes()
+    }
+}
+
+/// secp256k1 ephemeral secret
+#[cfg(feature = "secp256k1")]
+pub type Secp256k1EphemeralSecret = EphemeralSecret<Secp256k1>;
+
+/// secp256k1 shared secret
+#[cfg(feature = "secp256k1")]
+pub type Secp256k1SharedSecret = SharedSecret<Secp256k1>;
+
+/// P-256 ephemeral secret
+#[cfg(feature = "nistp256")]
+pub type NistP256EphemeralSecret = EphemeralSecret<NistP256>;
+
+/// P-256 shared secret
+#[cfg(feature = "nistp256")]
+pub type NistP256SharedSecret = SharedSecret<NistP256>;
+
+#[cfg(all(test, feature = "secp256k1"))]
+mod tests {
+    use super::{Secp256k1EphemeralSecret, Secp256k1SharedSecret};
+
+    #[test]
+    fn diffie_hellman_roundtrip() {
+        let alice = Secp256k1EphemeralSecret::generate(&mut rand_core::OsRng);
+        let bob = Secp256k1EphemeralSecret::generate(&mut rand_core::OsRng);
+
+        let alice_shared: Secp256k1SharedSecret = alice.diffie_hellman(&bob.public_key());
+        let bob_shared: Secp256k1SharedSecret = bob.diffie_hellman(&alice.public_key());
+
+        assert_eq!(alice_shared.raw_secret_bytes(), bob_shared.raw_secret_bytes());
+    }
+}
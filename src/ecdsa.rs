@@ -3,7 +3,20 @@
 #[cfg(feature = "secp256k1")]
 pub mod secp256k1;
 
+#[cfg(feature = "nistp256")]
+pub mod nistp256;
+
 mod keyring;
+pub mod recoverable;
+
+#[cfg(feature = "jwk")]
+pub mod jwk;
+
+pub use self::{keyring::KeyRing, recoverable::RecoverableSignature};
+pub use ecdsa::{elliptic_curve, RecoveryId, Signature};
 
-pub use self::keyring::KeyRing;
-pub use ecdsa::{elliptic_curve, Signature};
+// Brings `PublicKey::to_public_key_der`/`to_public_key_pem` and
+// `PublicKey::from_public_key_der`/`from_public_key_pem` into scope for the
+// `elliptic_curve::PublicKey<C>` type aliased by each curve's module.
+#[cfg(feature = "std")]
+pub use pkcs8::{DecodePublicKey, EncodePublicKey};
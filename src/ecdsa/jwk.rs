@@ -0,0 +1,10 @@
+//! JSON Web Key (JWK) support for ECDSA keys, as described in RFC 7518 §6.2.
+//!
+//! This is a thin re-export of the `elliptic_curve` crate's own `jwk`
+//! feature: [`PublicKey::to_jwk`]/[`PublicKey::from_jwk`] and the
+//! equivalent methods on [`ecdsa::SigningKey`] already emit/parse
+//! `"kty":"EC"` JWKs with base64url-encoded SEC1 coordinates, keyed by
+//! curve name (`"P-256"`, `"secp256k1"`, ...) — there's nothing curve- or
+//! signatory-specific left to wrap.
+
+pub use elliptic_curve::JwkEcKey as Jwk;
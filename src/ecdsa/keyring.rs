@@ -0,0 +1,6 @@
+//! A [`crate::KeyRing`] specialized for ECDSA signers
+
+use crate::KeyRing as GenericKeyRing;
+
+/// A named collection of ECDSA signers, keyed by label.
+pub type KeyRing<S> = GenericKeyRing<S>;
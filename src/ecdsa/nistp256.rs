@@ -0,0 +1,51 @@
+//! ECDSA/NIST P-256 provider, backed by the RustCrypto [`p256`] crate.
+
+use crate::{Error, Result};
+use ecdsa::signature::Signer as _;
+use p256::NistP256;
+
+/// P-256 public key
+pub type PublicKey = elliptic_curve::PublicKey<NistP256>;
+
+/// P-256 fixed-width `r ‖ s` signature
+pub type FixedSignature = ecdsa::Signature<NistP256>;
+
+/// P-256 ECDSA signing key
+pub struct SigningKey(ecdsa::SigningKey<NistP256>);
+
+impl SigningKey {
+    /// Compute the public key which corresponds to this signer's secret key.
+    pub fn public_key(&self) -> PublicKey {
+        *self.0.verifying_key().as_ref()
+    }
+
+    /// Parse a signing key from a raw 32-byte secret scalar, e.g. one
+    /// derived via [`crate::derivation::derive`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        ecdsa::SigningKey::from_slice(bytes)
+            .map(Self)
+            .map_err(|_| Error::KeyInvalid)
+    }
+}
+
+impl ecdsa::signature::Signer<FixedSignature> for SigningKey {
+    fn try_sign(&self, msg: &[u8]) -> core::result::Result<FixedSignature, ecdsa::signature::Error> {
+        self.0.try_sign(msg)
+    }
+}
+
+/// P-256 ECDSA verifying key
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VerifyingKey(ecdsa::VerifyingKey<NistP256>);
+
+impl From<&PublicKey> for VerifyingKey {
+    fn from(public_key: &PublicKey) -> Self {
+        VerifyingKey(ecdsa::VerifyingKey::from(public_key))
+    }
+}
+
+impl ecdsa::signature::Verifier<FixedSignature> for VerifyingKey {
+    fn verify(&self, msg: &[u8], signature: &FixedSignature) -> core::result::Result<(), ecdsa::signature::Error> {
+        ecdsa::signature::Verifier::verify(&self.0, msg, signature)
+    }
+}
@@ -0,0 +1,105 @@
+//! Recoverable ECDSA signatures.
+//!
+//! A [`RecoverableSignature`] is a fixed-width `r ‖ s` [`Signature`] plus a
+//! 1-byte recovery identifier `v ∈ 0..=3`, which together are enough to
+//! recover the signer's public key from `(msg, signature)` alone — the
+//! scheme used by Ethereum and Bitcoin "compact signatures".
+
+use super::Signature;
+use crate::{Error, Result};
+use ecdsa::{
+    hazmat::{DigestPrimitive, VerifyPrimitive},
+    signature::Verifier as _,
+    CurveArithmetic, PrimeCurve, RecoveryId, SignatureSize, VerifyingKey,
+};
+use elliptic_curve::{generic_array::ArrayLength, sec1::ModulusSize, FieldBytesSize, PublicKey};
+
+/// A [`Signature`] bundled with the [`RecoveryId`] needed to recover the
+/// public key of whoever produced it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecoverableSignature<C>
+where
+    C: PrimeCurve + CurveArithmetic,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    signature: Signature<C>,
+    recovery_id: RecoveryId,
+}
+
+impl<C> RecoverableSignature<C>
+where
+    C: PrimeCurve + CurveArithmetic,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    /// Combine a fixed-width `r ‖ s` signature with its recovery identifier.
+    pub fn new(signature: Signature<C>, recovery_id: RecoveryId) -> Self {
+        Self {
+            signature,
+            recovery_id,
+        }
+    }
+
+    /// The `r ‖ s` portion of this signature.
+    pub fn signature(&self) -> &Signature<C> {
+        &self.signature
+    }
+
+    /// The recovery identifier `v`, encoding the parity of the ephemeral
+    /// point's y-coordinate and whether its x-coordinate exceeded the
+    /// curve order.
+    pub fn recovery_id(&self) -> RecoveryId {
+        self.recovery_id
+    }
+
+    /// Recover the public key of the signer of `msg`.
+    ///
+    /// Once a candidate public key has been reconstructed, it is
+    /// re-verified against `(msg, signature)` exactly as an ordinary
+    /// [`Verifier`][ecdsa::signature::Verifier] would, and recovery fails
+    /// if that check fails. This check does not detect a wrong `v` or a
+    /// different `msg`: either one yields a different public key, for which
+    /// the signature still verifies.
+    pub fn recover_verifier(&self, msg: &[u8]) -> Result<PublicKey<C>>
+    where
+        C: DigestPrimitive,
+        VerifyingKey<C>: VerifyPrimitive<C>,
+        FieldBytesSize<C>: ModulusSize,
+    {
+        let candidate =
+            VerifyingKey::<C>::recover_from_msg(msg, &self.signature, self.recovery_id)
+                .map_err(|_| Error::Recovery)?;
+
+        // Recompute verification against the candidate key as an invariant
+        // check on the recovery arithmetic.
+        candidate
+            .verify(msg, &self.signature)
+            .map_err(|_| Error::Recovery)?;
+
+        Ok(candidate.into())
+    }
+}
+
+#[cfg(all(test, feature = "secp256k1"))]
+mod tests {
+    use crate::ecdsa::secp256k1::{self, SigningKey};
+
+    #[test]
+    fn recovers_signer_public_key() {
+        let signing_key = SigningKey::from_bytes(&[42u8; 32]).unwrap();
+        let msg = b"recoverable signature round-trip";
+
+        let signature = signing_key.sign_recoverable(msg).unwrap();
+        let recovered = secp256k1::recover_verifier(msg, &signature).unwrap();
+
+        assert_eq!(recovered, signing_key.public_key());
+    }
+
+    #[test]
+    fn tweaked_message_does_not_recover_signer_key() {
+        let signing_key = SigningKey::from_bytes(&[7u8; 32]).unwrap();
+        let signature = signing_key.sign_recoverable(b"original message").unwrap();
+
+        let recovered = secp256k1::recover_verifier(b"tampered message", &signature);
+        assert_ne!(recovered.ok(), Some(signing_key.public_key()));
+    }
+}
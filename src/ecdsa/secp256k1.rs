@@ -0,0 +1,97 @@
+//! ECDSA/secp256k1 provider, backed by the RustCrypto [`k256`] crate.
+
+pub mod schnorr;
+
+use super::recoverable::RecoverableSignature;
+use crate::{keystore::GeneratePkcs8, Error, Result};
+use ecdsa::{signature::Signer as _, RecoveryId};
+use k256::Secp256k1;
+
+/// secp256k1 public key
+pub type PublicKey = elliptic_curve::PublicKey<Secp256k1>;
+
+/// secp256k1 fixed-width `r ‖ s` signature
+pub type FixedSignature = ecdsa::Signature<Secp256k1>;
+
+/// secp256k1 ECDSA signing key
+pub struct SigningKey(ecdsa::SigningKey<Secp256k1>);
+
+impl SigningKey {
+    /// Compute the public key which corresponds to this signer's secret key.
+    pub fn public_key(&self) -> PublicKey {
+        *self.0.verifying_key().as_ref()
+    }
+
+    /// Sign `msg`, producing a [`RecoverableSignature`] whose recovery `v`
+    /// is determined by the parity of the ephemeral point `R`'s
+    /// y-coordinate and whether its x-coordinate exceeded the curve order.
+    pub fn sign_recoverable(&self, msg: &[u8]) -> Result<RecoverableSignature<Secp256k1>> {
+        let (signature, recovery_id): (FixedSignature, RecoveryId) =
+            self.0.sign_recoverable(msg).map_err(|_| Error::Recovery)?;
+
+        Ok(RecoverableSignature::new(signature, recovery_id))
+    }
+
+    /// Parse a signing key from its base58-encoded raw secret scalar, the
+    /// convention used by keypair files in the Solana ecosystem.
+    pub fn from_base58(encoded: &str) -> Result<Self> {
+        let bytes = bs58::decode(encoded)
+            .into_vec()
+            .map_err(|_| Error::KeyInvalid)?;
+
+        Self::from_bytes(&bytes)
+    }
+
+    /// Encode this signing key's raw secret scalar as base58.
+    pub fn to_base58(&self) -> alloc::string::String {
+        bs58::encode(self.0.to_bytes()).into_string()
+    }
+
+    /// Parse a signing key from a raw 32-byte secret scalar, e.g. one
+    /// derived via [`crate::derivation::derive`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        ecdsa::SigningKey::from_slice(bytes)
+            .map(Self)
+            .map_err(|_| Error::KeyInvalid)
+    }
+}
+
+impl GeneratePkcs8 for SigningKey {
+    fn generate_pkcs8() -> pkcs8::PrivateKeyDocument {
+        let signing_key = ecdsa::SigningKey::<Secp256k1>::random(&mut rand_core::OsRng);
+        pkcs8::PrivateKeyDocument::from_pkcs8_der(
+            pkcs8::EncodePrivateKey::to_pkcs8_der(&signing_key)
+                .expect("PKCS#8 encoding failure")
+                .as_bytes(),
+        )
+        .expect("PKCS#8 round-trip failure")
+    }
+}
+
+impl ecdsa::signature::Signer<FixedSignature> for SigningKey {
+    fn try_sign(&self, msg: &[u8]) -> core::result::Result<FixedSignature, ecdsa::signature::Error> {
+        self.0.try_sign(msg)
+    }
+}
+
+/// secp256k1 ECDSA verifying key
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VerifyingKey(ecdsa::VerifyingKey<Secp256k1>);
+
+impl From<&PublicKey> for VerifyingKey {
+    fn from(public_key: &PublicKey) -> Self {
+        VerifyingKey(ecdsa::VerifyingKey::from(public_key))
+    }
+}
+
+impl ecdsa::signature::Verifier<FixedSignature> for VerifyingKey {
+    fn verify(&self, msg: &[u8], signature: &FixedSignature) -> core::result::Result<(), ecdsa::signature::Error> {
+        ecdsa::signature::Verifier::verify(&self.0, msg, signature)
+    }
+}
+
+/// Recover the public key of the signer of `msg` from a secp256k1
+/// [`RecoverableSignature`] alone.
+pub fn recover_verifier(msg: &[u8], signature: &RecoverableSignature<Secp256k1>) -> Result<PublicKey> {
+    signature.recover_verifier(msg)
+}
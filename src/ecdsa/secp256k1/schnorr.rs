@@ -0,0 +1,82 @@
+//! BIP340 Schnorr signatures for secp256k1.
+//!
+//! Thin wrapper around [`k256::schnorr`], reusing the crate's own
+//! [`Signature`][ecdsa::signature::Signature]/[`Signer`][ecdsa::signature::Signer]/
+//! [`Verifier`][ecdsa::signature::Verifier] traits so Schnorr keys slot into
+//! the same keystore and keyring plumbing as the ECDSA types in the parent
+//! module.
+
+use ecdsa::signature::{self, Signer as _, Verifier as _};
+
+/// BIP340 x-only public key (32 bytes, even-y normalized)
+pub type PublicKey = k256::schnorr::VerifyingKey;
+
+/// BIP340 Schnorr signature (64 bytes: `R_x ‖ s`)
+pub type Signature = k256::schnorr::Signature;
+
+/// BIP340 Schnorr signing key
+pub struct SigningKey(k256::schnorr::SigningKey);
+
+impl SigningKey {
+    /// Compute the x-only public key which corresponds to this signer's
+    /// secret key.
+    pub fn public_key(&self) -> PublicKey {
+        *self.0.verifying_key()
+    }
+}
+
+impl signature::Signer<Signature> for SigningKey {
+    /// Sign `msg`, computing the nonce via the tagged hash
+    /// `k = H_tag("BIP0340/nonce", aux_rand ‖ P ‖ m)` and the challenge via
+    /// `e = H_tag("BIP0340/challenge", R_x ‖ P_x ‖ m) mod n`.
+    fn try_sign(&self, msg: &[u8]) -> core::result::Result<Signature, signature::Error> {
+        self.0.try_sign(msg)
+    }
+}
+
+/// BIP340 Schnorr verifying key
+#[derive(Clone, Debug)]
+pub struct VerifyingKey(PublicKey);
+
+impl From<&PublicKey> for VerifyingKey {
+    fn from(public_key: &PublicKey) -> Self {
+        VerifyingKey(*public_key)
+    }
+}
+
+impl signature::Verifier<Signature> for VerifyingKey {
+    /// Verify `s·G = R + e·P`, rejecting signatures whose `R` has odd y.
+    fn verify(&self, msg: &[u8], signature: &Signature) -> core::result::Result<(), signature::Error> {
+        self.0.verify(msg, signature)
+    }
+}
+
+// TODO(tarcieri): wire in the official BIP340 test vectors. Doing so needs a
+// way to supply a fixed `aux_rand` to `SigningKey::try_sign` for
+// deterministic reproduction, which this thin wrapper doesn't expose yet.
+#[cfg(test)]
+mod tests {
+    use super::{SigningKey, VerifyingKey};
+    use ecdsa::signature::{Signer as _, Verifier as _};
+    use k256::schnorr::SigningKey as InnerSigningKey;
+
+    #[test]
+    fn sign_and_verify_roundtrip() {
+        let signer = SigningKey(InnerSigningKey::from_bytes(&[1u8; 32]).unwrap());
+        let msg = b"BIP340 schnorr round-trip";
+
+        let signature = signer.try_sign(msg).unwrap();
+        let verifier = VerifyingKey::from(&signer.public_key());
+
+        assert!(verifier.verify(msg, &signature).is_ok());
+    }
+
+    #[test]
+    fn rejects_tweaked_signature() {
+        let signer = SigningKey(InnerSigningKey::from_bytes(&[2u8; 32]).unwrap());
+        let signature = signer.try_sign(b"original message").unwrap();
+
+        let verifier = VerifyingKey::from(&signer.public_key());
+        assert!(verifier.verify(b"tampered message", &signature).is_err());
+    }
+}
@@ -21,6 +21,24 @@ pub enum Error {
 
     /// PKCS#8 errors
     Pkcs8(pkcs8::Error),
+
+    /// Public key recovery failed, e.g. because the recovery ID did not
+    /// match the signature, or the recovered key did not verify
+    Recovery,
+
+    /// JSON Web Key (JWK) was malformed or used the wrong `kty`/`crv`
+    #[cfg(feature = "jwk")]
+    Jwk,
+
+    /// Malformed BIP32 derivation path, or a non-hardened index used with a
+    /// hardened-only curve (e.g. Ed25519)
+    DerivationPath,
+
+    /// Signature failed to verify
+    SignatureInvalid,
+
+    /// Malformed or invalid key material
+    KeyInvalid,
 }
 
 impl From<pkcs8::Error> for Error {
@@ -0,0 +1,45 @@
+//! Collections of signing keys, keyed by label
+
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+
+/// A named collection of signing keys.
+///
+/// `KeyRing` is a thin, in-memory map from a label to a key of type `K`.
+/// It's the in-process counterpart to [`crate::keystore::fs::FsKeyStore`]:
+/// keystores persist keys to disk, keyrings hold the keys an application
+/// actually uses at runtime.
+pub struct KeyRing<K> {
+    keys: BTreeMap<String, K>,
+}
+
+impl<K> KeyRing<K> {
+    /// Create a new, empty keyring.
+    pub fn new() -> Self {
+        Self {
+            keys: BTreeMap::new(),
+        }
+    }
+
+    /// Add a key to the keyring under the given label, returning the
+    /// previous key stored under that label, if any.
+    pub fn insert(&mut self, label: impl Into<String>, key: K) -> Option<K> {
+        self.keys.insert(label.into(), key)
+    }
+
+    /// Look up a key by its label.
+    pub fn get(&self, label: &str) -> Option<&K> {
+        self.keys.get(label)
+    }
+
+    /// Remove a key from the keyring, returning it if present.
+    pub fn remove(&mut self, label: &str) -> Option<K> {
+        self.keys.remove(label)
+    }
+}
+
+impl<K> Default for KeyRing<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
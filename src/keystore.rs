@@ -0,0 +1,11 @@
+//! Key storage backends.
+
+pub mod fs;
+
+pub use self::fs::FsKeyStore;
+
+/// Randomly generate a PKCS#8-encoded keypair for a signer type.
+pub trait GeneratePkcs8 {
+    /// Randomly generate a new keypair, returning it as a PKCS#8 document.
+    fn generate_pkcs8() -> pkcs8::PrivateKeyDocument;
+}
@@ -17,6 +17,30 @@ pub type Label = str;
 #[cfg(unix)]
 const REQUIRED_DIR_MODE: u32 = 0o700;
 
+/// On-disk encoding for a keypair, as an alternative to PKCS#8 PEM.
+///
+/// Mirrors the keypair file conventions used by Solana tooling: a base58
+/// string, or a JSON array of the raw secret key bytes.
+#[cfg(feature = "base58")]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum KeyFormat {
+    /// Base58-encoded raw secret key bytes
+    Base58,
+
+    /// JSON array of raw secret key bytes, e.g. `[1,2,3,...]`
+    JsonByteArray,
+}
+
+#[cfg(feature = "base58")]
+impl KeyFormat {
+    fn extension(self) -> &'static str {
+        match self {
+            KeyFormat::Base58 => "b58",
+            KeyFormat::JsonByteArray => "json",
+        }
+    }
+}
+
 /// Filesystem-backed keystore.
 pub struct FsKeyStore {
     path: PathBuf,
@@ -66,6 +90,21 @@ impl FsKeyStore {
         )?)
     }
 
+    /// Export a verifier's matching public key as a standard SPKI
+    /// `.pub.pem` file, so it can be distributed without the private key.
+    pub fn store_public_key(&self, label: &Label, der: &pkcs8::PublicKeyDocument) -> Result<()> {
+        der.write_pem_file(&self.public_key_path(label))?;
+        Ok(())
+    }
+
+    /// Load a public key previously exported with
+    /// [`FsKeyStore::store_public_key`].
+    pub fn load_public_key(&self, label: &Label) -> Result<pkcs8::PublicKeyDocument> {
+        Ok(pkcs8::PublicKeyDocument::read_pem_file(
+            &self.public_key_path(label),
+        )?)
+    }
+
     /// Delete a PKCS#8 key from the keystore.
     pub fn delete(&self, label: &Label) -> Result<()> {
         fs::remove_file(&self.key_path(label))?;
@@ -73,6 +112,21 @@ impl FsKeyStore {
         Ok(())
     }
 
+    /// Import a key encoded as a JSON Web Key (JWK) into the keystore.
+    #[cfg(feature = "jwk")]
+    pub fn store_jwk(&self, label: &Label, jwk: &crate::ecdsa::jwk::Jwk) -> Result<()> {
+        fs::write(self.jwk_path(label), jwk.to_string())?;
+        Ok(())
+    }
+
+    /// Load a key previously stored as a JSON Web Key (JWK).
+    #[cfg(feature = "jwk")]
+    pub fn load_jwk(&self, label: &Label) -> Result<crate::ecdsa::jwk::Jwk> {
+        use core::str::FromStr;
+        let json = fs::read_to_string(self.jwk_path(label))?;
+        crate::ecdsa::jwk::Jwk::from_str(&json).map_err(|_| Error::Jwk)
+    }
+
     /// Compute the path for a key with a given label.
     fn key_path(&self, label: &Label) -> PathBuf {
         // TODO(tarcieri): extract `Label` type and validate label
@@ -80,6 +134,103 @@ impl FsKeyStore {
         path.set_extension("pem");
         path
     }
+
+    /// Compute the path for a public key with a given label.
+    fn public_key_path(&self, label: &Label) -> PathBuf {
+        let mut path = self.path.join(label);
+        path.set_extension("pub.pem");
+        path
+    }
+
+    /// Compute the path for a JWK-encoded key with a given label.
+    #[cfg(feature = "jwk")]
+    fn jwk_path(&self, label: &Label) -> PathBuf {
+        let mut path = self.path.join(label);
+        path.set_extension("jwk");
+        path
+    }
+
+    /// Store a master seed under `label`, to later derive child keys from
+    /// via [`FsKeyStore::derive_child`]. Unlike [`FsKeyStore::store`], this
+    /// is raw seed material, not a PKCS#8 document.
+    pub fn store_seed(&self, label: &Label, seed: &[u8]) -> Result<()> {
+        fs::write(self.seed_path(label), seed)?;
+        Ok(())
+    }
+
+    /// Derive a child key from the master seed stored under `seed_label`,
+    /// following a SLIP-0010/BIP32 `path`. The derived leaf is never
+    /// persisted — only the master seed is stored on disk.
+    pub fn derive_child(
+        &self,
+        seed_label: &Label,
+        curve: crate::derivation::Curve,
+        path: &crate::derivation::DerivationPath,
+    ) -> Result<crate::derivation::ExtendedKey> {
+        let seed = fs::read(self.seed_path(seed_label))?;
+        crate::derivation::derive(curve, &seed, path)
+    }
+
+    /// Compute the path for a master seed with a given label.
+    fn seed_path(&self, label: &Label) -> PathBuf {
+        let mut path = self.path.join(label);
+        path.set_extension("seed");
+        path
+    }
+
+    /// Import raw secret key bytes into the keystore using `format`, e.g.
+    /// a base58 or JSON-byte-array keypair file exported from wallet
+    /// tooling that doesn't speak PKCS#8.
+    #[cfg(feature = "base58")]
+    pub fn store_keypair(&self, label: &Label, format: KeyFormat, secret_key: &[u8]) -> Result<()> {
+        let encoded = match format {
+            KeyFormat::Base58 => bs58::encode(secret_key).into_string(),
+            KeyFormat::JsonByteArray => {
+                let digits: Vec<String> = secret_key.iter().map(u8::to_string).collect();
+                format!("[{}]", digits.join(","))
+            }
+        };
+
+        fs::write(self.keypair_path(label, format), encoded)?;
+        Ok(())
+    }
+
+    /// Load raw secret key bytes previously stored with
+    /// [`FsKeyStore::store_keypair`] in the given `format`.
+    #[cfg(feature = "base58")]
+    pub fn load_keypair(&self, label: &Label, format: KeyFormat) -> Result<Vec<u8>> {
+        let contents = fs::read_to_string(self.keypair_path(label, format))?;
+        let trimmed = contents.trim();
+
+        match format {
+            KeyFormat::Base58 => bs58::decode(trimmed)
+                .into_vec()
+                .map_err(|_| Error::KeyInvalid),
+            KeyFormat::JsonByteArray => {
+                let inner = trimmed
+                    .trim_start_matches('[')
+                    .trim_end_matches(']')
+                    .trim();
+
+                if inner.is_empty() {
+                    return Ok(Vec::new());
+                }
+
+                inner
+                    .split(',')
+                    .map(|digit| digit.trim().parse().map_err(|_| Error::KeyInvalid))
+                    .collect()
+            }
+        }
+    }
+
+    /// Compute the path for a keypair file of the given format.
+    #[cfg(feature = "base58")]
+    fn keypair_path(&self, label: &Label, format: KeyFormat) -> PathBuf {
+        let mut path = self.path.join(label);
+        path.set_extension(format.extension());
+        path
+    }
 }
 
 #[cfg(test)]
@@ -105,4 +256,61 @@ mod tests {
 
         keystore.delete(label).unwrap();
     }
+
+    #[cfg(feature = "base58")]
+    #[test]
+    fn keypair_roundtrip_base58() {
+        use super::KeyFormat;
+
+        let dir = tempfile::tempdir().unwrap();
+        let keystore = FsKeyStore::create(&dir.path().join("keys")).unwrap();
+
+        let label = "example_keypair";
+        let secret_key = [42u8; 32];
+        keystore
+            .store_keypair(label, KeyFormat::Base58, &secret_key)
+            .unwrap();
+
+        let loaded = keystore.load_keypair(label, KeyFormat::Base58).unwrap();
+        assert_eq!(loaded, secret_key);
+    }
+
+    #[cfg(feature = "base58")]
+    #[test]
+    fn keypair_roundtrip_json_byte_array() {
+        use super::KeyFormat;
+
+        let dir = tempfile::tempdir().unwrap();
+        let keystore = FsKeyStore::create(&dir.path().join("keys")).unwrap();
+
+        let label = "example_keypair";
+        let secret_key = [7u8; 32];
+        keystore
+            .store_keypair(label, KeyFormat::JsonByteArray, &secret_key)
+            .unwrap();
+
+        let loaded = keystore
+            .load_keypair(label, KeyFormat::JsonByteArray)
+            .unwrap();
+        assert_eq!(loaded, secret_key);
+    }
+
+    #[cfg(feature = "base58")]
+    #[test]
+    fn keypair_roundtrip_json_byte_array_empty() {
+        use super::KeyFormat;
+
+        let dir = tempfile::tempdir().unwrap();
+        let keystore = FsKeyStore::create(&dir.path().join("keys")).unwrap();
+
+        let label = "empty_keypair";
+        keystore
+            .store_keypair(label, KeyFormat::JsonByteArray, &[])
+            .unwrap();
+
+        let loaded = keystore
+            .load_keypair(label, KeyFormat::JsonByteArray)
+            .unwrap();
+        assert!(loaded.is_empty());
+    }
 }
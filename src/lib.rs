@@ -11,11 +11,15 @@ extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
+pub mod derivation;
 pub mod keystore;
 
 #[cfg(feature = "ecdsa")]
 pub mod ecdsa;
 
+#[cfg(feature = "ecdh")]
+pub mod ecdh;
+
 mod error;
 mod keyring;
 